@@ -1,11 +1,19 @@
+use std::path::PathBuf;
+
 use clap::{Parser, Subcommand};
 
+use crate::runner::Runner;
+
 /// Jeeves - Agent orchestration system
 #[derive(Parser, Debug)]
 #[command(name = "jeeves")]
 #[command(about = "Agent orchestration system for automated development workflows")]
 #[command(version)]
 pub struct Cli {
+    /// Path to a configuration file overriding the resolved default
+    #[arg(long, global = true)]
+    pub config: Option<PathBuf>,
+
     #[command(subcommand)]
     pub command: Commands,
 }
@@ -25,13 +33,51 @@ pub enum Commands {
 
     /// Run the orchestration loop
     Run {
-        /// Maximum number of iterations
-        #[arg(long, default_value = "10")]
-        max_iterations: u32,
+        /// Maximum number of iterations (overrides the configured default)
+        #[arg(long)]
+        max_iterations: Option<u32>,
+
+        /// Runner to use (overrides the configured default)
+        #[arg(long, value_enum)]
+        runner: Option<Runner>,
+
+        /// Keep running after a failing iteration and report every failure at
+        /// the end instead of aborting on the first one
+        #[arg(long)]
+        no_fail_fast: bool,
+
+        /// Issue to orchestrate; repeat to schedule a batch as a DAG
+        #[arg(long = "issue")]
+        issues: Vec<u32>,
+
+        /// Dependency between batch issues as `child:parent` (child waits on
+        /// parent); repeatable
+        #[arg(long = "after")]
+        after: Vec<String>,
+
+        /// Maximum number of issues to orchestrate concurrently in batch mode
+        #[arg(long, default_value = "2")]
+        parallelism: usize,
+    },
+
+    /// Run as a long-lived daemon reacting to GitHub webhook deliveries
+    Serve {
+        /// Address to bind the HTTP listener to (overrides the configured default)
+        #[arg(long)]
+        listen: Option<String>,
+
+        /// Shared secret used to verify the `X-Hub-Signature-256` header
+        /// (falls back to `webhook_secret` in the config file)
+        #[arg(long, env = "JEEVES_WEBHOOK_SECRET")]
+        secret: Option<String>,
+
+        /// Maximum number of orchestration runs to execute in parallel
+        #[arg(long, default_value = "2")]
+        max_jobs: usize,
 
-        /// Runner to use (claude, codex, opencode)
-        #[arg(long, default_value = "opencode")]
-        runner: String,
+        /// Per-job timeout in seconds (overrides the configured default)
+        #[arg(long)]
+        job_timeout: Option<u64>,
     },
 
     /// Fetch SonarCloud issues
@@ -65,9 +111,11 @@ mod tests {
             Commands::Run {
                 max_iterations,
                 runner,
+                ..
             } => {
-                assert_eq!(max_iterations, 10);
-                assert_eq!(runner, "opencode");
+                // Unset flags defer to the resolved config / built-in defaults.
+                assert_eq!(max_iterations, None);
+                assert_eq!(runner, None);
             }
             _ => panic!("Expected Run command"),
         }
@@ -87,14 +135,79 @@ mod tests {
             Commands::Run {
                 max_iterations,
                 runner,
+                ..
+            } => {
+                assert_eq!(max_iterations, Some(20));
+                assert_eq!(runner, Some(Runner::Claude));
+            }
+            _ => panic!("Expected Run command"),
+        }
+    }
+
+    #[test]
+    fn test_run_subcommand_no_fail_fast() {
+        let cli = Cli::parse_from(["jeeves", "run", "--no-fail-fast"]);
+        match cli.command {
+            Commands::Run { no_fail_fast, .. } => assert!(no_fail_fast),
+            _ => panic!("Expected Run command"),
+        }
+
+        let cli = Cli::parse_from(["jeeves", "run"]);
+        match cli.command {
+            Commands::Run { no_fail_fast, .. } => assert!(!no_fail_fast),
+            _ => panic!("Expected Run command"),
+        }
+    }
+
+    #[test]
+    fn test_run_subcommand_batch() {
+        let cli = Cli::parse_from([
+            "jeeves", "run", "--issue", "1", "--issue", "2", "--after", "2:1", "--parallelism",
+            "3",
+        ]);
+        match cli.command {
+            Commands::Run {
+                issues,
+                after,
+                parallelism,
+                ..
             } => {
-                assert_eq!(max_iterations, 20);
-                assert_eq!(runner, "claude");
+                assert_eq!(issues, vec![1, 2]);
+                assert_eq!(after, vec!["2:1".to_string()]);
+                assert_eq!(parallelism, 3);
             }
             _ => panic!("Expected Run command"),
         }
     }
 
+    #[test]
+    fn test_serve_subcommand() {
+        let cli = Cli::parse_from([
+            "jeeves",
+            "serve",
+            "--listen",
+            "127.0.0.1:9000",
+            "--secret",
+            "hunter2",
+            "--max-jobs",
+            "4",
+        ]);
+        match cli.command {
+            Commands::Serve {
+                listen,
+                secret,
+                max_jobs,
+                job_timeout,
+            } => {
+                assert_eq!(listen.as_deref(), Some("127.0.0.1:9000"));
+                assert_eq!(secret.as_deref(), Some("hunter2"));
+                assert_eq!(max_jobs, 4);
+                assert_eq!(job_timeout, None);
+            }
+            _ => panic!("Expected Serve command"),
+        }
+    }
+
     #[test]
     fn test_sonar_subcommand() {
         let cli = Cli::parse_from(["jeeves", "sonar"]);
@@ -0,0 +1,180 @@
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::runner::Runner;
+
+/// Default per-job timeout when none is configured: one hour.
+const DEFAULT_JOB_TIMEOUT: Duration = Duration::from_secs(3600);
+
+/// Persistent settings loaded from a configuration file so that runner, repo,
+/// and credential values don't have to be repeated as CLI flags.
+///
+/// Precedence is resolved by the caller: CLI flags override values loaded here,
+/// which in turn override the built-in [`Default`] values.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    pub runner: Runner,
+    pub max_iterations: u32,
+    pub repo: Option<String>,
+    pub sonar_project: Option<String>,
+    pub sonar_token: Option<String>,
+    pub webhook_secret: Option<String>,
+    pub listen: Option<String>,
+    pub job_timeout: Duration,
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config {
+            runner: Runner::Opencode,
+            max_iterations: 10,
+            repo: None,
+            sonar_project: None,
+            sonar_token: None,
+            webhook_secret: None,
+            listen: None,
+            job_timeout: DEFAULT_JOB_TIMEOUT,
+        }
+    }
+}
+
+impl Config {
+    /// Load a [`Config`] from an INI-style `key = value` file, starting from the
+    /// built-in defaults and overriding each recognised key. Blank lines,
+    /// `#`/`;` comments, and a leading `[section]` header are ignored.
+    ///
+    /// Returns a human-readable error naming the offending line on any parse or
+    /// read failure.
+    pub fn from_path(path: &Path) -> Result<Config, String> {
+        let contents = std::fs::read_to_string(path)
+            .map_err(|e| format!("failed to read config {}: {e}", path.display()))?;
+        let mut config = Config::default();
+
+        for (index, raw) in contents.lines().enumerate() {
+            let line = strip_comment(raw).trim();
+            if line.is_empty() || (line.starts_with('[') && line.ends_with(']')) {
+                continue;
+            }
+            let lineno = index + 1;
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| format!("{}:{lineno}: expected `key = value`", path.display()))?;
+            config.apply(key.trim(), value.trim(), &path.display().to_string(), lineno)?;
+        }
+        Ok(config)
+    }
+
+    fn apply(&mut self, key: &str, value: &str, source: &str, lineno: usize) -> Result<(), String> {
+        match key {
+            "runner" => {
+                self.runner = parse_runner(value)
+                    .ok_or_else(|| format!("{source}:{lineno}: unknown runner `{value}`"))?;
+            }
+            "max_iterations" => {
+                self.max_iterations = value.parse().map_err(|_| {
+                    format!("{source}:{lineno}: max_iterations must be a number, got `{value}`")
+                })?;
+            }
+            "repo" => self.repo = Some(value.to_string()),
+            "sonar_project" => self.sonar_project = Some(value.to_string()),
+            "sonar_token" => self.sonar_token = Some(value.to_string()),
+            "webhook_secret" => self.webhook_secret = Some(value.to_string()),
+            "listen" => self.listen = Some(value.to_string()),
+            "job_timeout" => {
+                let secs = value.parse().map_err(|_| {
+                    format!("{source}:{lineno}: job_timeout must be seconds, got `{value}`")
+                })?;
+                self.job_timeout = Duration::from_secs(secs);
+            }
+            other => return Err(format!("{source}:{lineno}: unknown key `{other}`")),
+        }
+        Ok(())
+    }
+}
+
+/// Resolve the configuration path to load: an explicit `--config`, then
+/// `$XDG_CONFIG_HOME/jeeves/config`, then the default `~/.config/jeeves/config`.
+/// Returns `None` when no candidate exists so the caller can fall back to
+/// [`Config::default`].
+pub fn resolve_config_path(explicit: Option<PathBuf>) -> Option<PathBuf> {
+    if let Some(path) = explicit {
+        return Some(path);
+    }
+    let bases = [
+        std::env::var_os("XDG_CONFIG_HOME").map(PathBuf::from),
+        std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")),
+    ];
+    bases
+        .into_iter()
+        .flatten()
+        .map(|base| base.join("jeeves").join("config"))
+        .find(|candidate| candidate.exists())
+}
+
+fn strip_comment(line: &str) -> &str {
+    match line.find(['#', ';']) {
+        Some(idx) => &line[..idx],
+        None => line,
+    }
+}
+
+fn parse_runner(value: &str) -> Option<Runner> {
+    match value {
+        "claude" => Some(Runner::Claude),
+        "codex" => Some(Runner::Codex),
+        "opencode" => Some(Runner::Opencode),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    fn write_temp(contents: &str) -> PathBuf {
+        let mut path = std::env::temp_dir();
+        path.push(format!("jeeves-config-test-{}", contents.len()));
+        let mut file = std::fs::File::create(&path).unwrap();
+        file.write_all(contents.as_bytes()).unwrap();
+        path
+    }
+
+    #[test]
+    fn defaults_are_applied() {
+        let config = Config::default();
+        assert_eq!(config.runner, Runner::Opencode);
+        assert_eq!(config.max_iterations, 10);
+        assert_eq!(config.job_timeout, Duration::from_secs(3600));
+    }
+
+    #[test]
+    fn parses_known_keys() {
+        let path = write_temp(
+            "# jeeves config\n[defaults]\nrunner = claude\nmax_iterations = 25\nrepo = owner/repo\njob_timeout = 120 ; two minutes\n",
+        );
+        let config = Config::from_path(&path).unwrap();
+        assert_eq!(config.runner, Runner::Claude);
+        assert_eq!(config.max_iterations, 25);
+        assert_eq!(config.repo.as_deref(), Some("owner/repo"));
+        assert_eq!(config.job_timeout, Duration::from_secs(120));
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn reports_unknown_key_with_line_number() {
+        let path = write_temp("runner = claude\nbogus = 1\n");
+        let err = Config::from_path(&path).unwrap_err();
+        assert!(err.contains(":2:"), "error was: {err}");
+        assert!(err.contains("unknown key `bogus`"), "error was: {err}");
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn reports_malformed_line() {
+        let path = write_temp("runner claude\n");
+        let err = Config::from_path(&path).unwrap_err();
+        assert!(err.contains("expected `key = value`"), "error was: {err}");
+        std::fs::remove_file(&path).ok();
+    }
+}
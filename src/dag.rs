@@ -0,0 +1,473 @@
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread;
+
+use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
+
+use crate::runner::Runner;
+
+/// A single issue to orchestrate together with the issues it depends on. A node
+/// only starts once every issue in `depends_on` has completed successfully.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IssueNode {
+    pub issue: u32,
+    pub depends_on: Vec<u32>,
+}
+
+/// The terminal state of a scheduled issue.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NodeState {
+    /// Orchestration finished with a successful iteration.
+    Succeeded,
+    /// Orchestration exhausted its iterations or a runner failed.
+    Failed,
+    /// Skipped because one of its predecessors did not succeed.
+    Skipped,
+}
+
+/// Build the issue list from repeated `--issue` values and `child:parent`
+/// dependency specs, returning a human-readable error on a malformed spec or a
+/// reference to an unknown issue.
+pub fn build_nodes(issues: &[u32], deps: &[String]) -> Result<Vec<IssueNode>, String> {
+    let known: BTreeSet<u32> = issues.iter().copied().collect();
+    let mut edges: BTreeMap<u32, Vec<u32>> = issues.iter().map(|&i| (i, Vec::new())).collect();
+
+    for spec in deps {
+        let (child, parent) = spec
+            .split_once(':')
+            .ok_or_else(|| format!("invalid dependency `{spec}`: expected `child:parent`"))?;
+        let child = parse_issue(child, spec)?;
+        let parent = parse_issue(parent, spec)?;
+        if !known.contains(&child) {
+            return Err(format!("dependency `{spec}` references unknown issue #{child}"));
+        }
+        if !known.contains(&parent) {
+            return Err(format!("dependency `{spec}` references unknown issue #{parent}"));
+        }
+        edges.get_mut(&child).expect("child is known").push(parent);
+    }
+
+    Ok(edges
+        .into_iter()
+        .map(|(issue, depends_on)| IssueNode { issue, depends_on })
+        .collect())
+}
+
+fn parse_issue(value: &str, spec: &str) -> Result<u32, String> {
+    value
+        .trim()
+        .parse()
+        .map_err(|_| format!("invalid dependency `{spec}`: `{value}` is not an issue number"))
+}
+
+/// Detect a dependency cycle, returning the issues that form it in order.
+///
+/// Uses a depth-first search and, on encountering a back edge, reconstructs the
+/// cycle from the current DFS stack so the error can name each issue involved.
+fn find_cycle(nodes: &BTreeMap<u32, Vec<u32>>) -> Option<Vec<u32>> {
+    #[derive(Clone, Copy, PartialEq)]
+    enum Mark {
+        Visiting,
+        Done,
+    }
+
+    let mut marks: BTreeMap<u32, Mark> = BTreeMap::new();
+    let mut stack: Vec<u32> = Vec::new();
+
+    fn dfs(
+        node: u32,
+        nodes: &BTreeMap<u32, Vec<u32>>,
+        marks: &mut BTreeMap<u32, Mark>,
+        stack: &mut Vec<u32>,
+    ) -> Option<Vec<u32>> {
+        marks.insert(node, Mark::Visiting);
+        stack.push(node);
+        for &next in nodes.get(&node).into_iter().flatten() {
+            match marks.get(&next) {
+                Some(Mark::Visiting) => {
+                    let start = stack.iter().position(|&n| n == next).unwrap_or(0);
+                    let mut cycle = stack[start..].to_vec();
+                    cycle.push(next);
+                    return Some(cycle);
+                }
+                Some(Mark::Done) => {}
+                None => {
+                    if let Some(cycle) = dfs(next, nodes, marks, stack) {
+                        return Some(cycle);
+                    }
+                }
+            }
+        }
+        stack.pop();
+        marks.insert(node, Mark::Done);
+        None
+    }
+
+    for &node in nodes.keys() {
+        if !marks.contains_key(&node) {
+            if let Some(cycle) = dfs(node, nodes, &mut marks, &mut stack) {
+                return Some(cycle);
+            }
+        }
+    }
+    None
+}
+
+/// Schedule a set of issues as a DAG: topologically ordered, independent issues
+/// run concurrently up to `parallelism`, and a dependent issue starts only once
+/// all of its predecessors have succeeded. Cycles are detected up front and
+/// reported with the issues involved.
+///
+/// Returns the terminal [`NodeState`] of every issue, keyed by issue number.
+pub fn schedule(
+    nodes: Vec<IssueNode>,
+    parallelism: usize,
+    max_iterations: u32,
+    runner: Runner,
+) -> Result<BTreeMap<u32, NodeState>, String> {
+    let adjacency: BTreeMap<u32, Vec<u32>> = nodes
+        .iter()
+        .map(|n| (n.issue, n.depends_on.clone()))
+        .collect();
+
+    if let Some(cycle) = find_cycle(&adjacency) {
+        let rendered = cycle
+            .iter()
+            .map(|i| format!("#{i}"))
+            .collect::<Vec<_>>()
+            .join(" -> ");
+        return Err(format!("dependency cycle detected: {rendered}"));
+    }
+
+    let total = nodes.len();
+    let progress = MultiProgress::new();
+    let overall = progress.add(ProgressBar::new(total as u64));
+    overall.set_style(
+        ProgressStyle::with_template("{prefix:>12} [{bar:30}] {pos}/{len} issues")
+            .expect("valid template")
+            .progress_chars("=> "),
+    );
+    overall.set_prefix("overall");
+
+    let mut scheduler = Scheduler::new(nodes);
+    let initial = scheduler.initially_ready();
+    if scheduler.remaining() == 0 {
+        return Ok(scheduler.results);
+    }
+    scheduler.ready.extend(initial);
+
+    // Workers pull ready issues off a shared queue guarded by a condvar; as each
+    // finishes it unblocks any dependents whose predecessors are now satisfied,
+    // and sets `done` once no issues remain so idle workers can exit.
+    let shared = Arc::new((Mutex::new(scheduler), Condvar::new()));
+    let progress = Arc::new(progress);
+    let overall = Arc::new(overall);
+
+    let mut workers = Vec::new();
+    for _ in 0..parallelism.max(1) {
+        let shared = Arc::clone(&shared);
+        let progress = Arc::clone(&progress);
+        let overall = Arc::clone(&overall);
+        workers.push(thread::spawn(move || {
+            worker(shared, progress, overall, max_iterations, runner);
+        }));
+    }
+
+    for worker in workers {
+        worker.join().expect("scheduler worker panicked");
+    }
+
+    overall.finish_and_clear();
+    let results = shared.0.lock().expect("scheduler poisoned").results.clone();
+    Ok(results)
+}
+
+/// Shared scheduling bookkeeping guarded by a single mutex.
+struct Scheduler {
+    /// Unsatisfied predecessor count per issue.
+    pending: BTreeMap<u32, usize>,
+    /// Reverse edges: which issues depend on a given issue.
+    dependents: BTreeMap<u32, Vec<u32>>,
+    /// Terminal state of each finished issue.
+    results: BTreeMap<u32, NodeState>,
+    /// Issues that have not yet reached a terminal state (including those
+    /// currently running).
+    outstanding: usize,
+    /// Issues whose predecessors are satisfied and are waiting for a worker.
+    ready: VecDeque<u32>,
+    /// Set once every issue has reached a terminal state.
+    done: bool,
+}
+
+impl Scheduler {
+    fn new(nodes: Vec<IssueNode>) -> Scheduler {
+        let mut pending = BTreeMap::new();
+        let mut dependents: BTreeMap<u32, Vec<u32>> = BTreeMap::new();
+        for node in &nodes {
+            pending.insert(node.issue, node.depends_on.len());
+            for &parent in &node.depends_on {
+                dependents.entry(parent).or_default().push(node.issue);
+            }
+        }
+        Scheduler {
+            outstanding: nodes.len(),
+            pending,
+            dependents,
+            results: BTreeMap::new(),
+            ready: VecDeque::new(),
+            done: false,
+        }
+    }
+
+    fn initially_ready(&self) -> Vec<u32> {
+        self.pending
+            .iter()
+            .filter(|(_, &count)| count == 0)
+            .map(|(&issue, _)| issue)
+            .collect()
+    }
+
+    fn remaining(&self) -> usize {
+        self.outstanding
+    }
+
+    /// Record an issue's outcome and return the issues that are now ready to run
+    /// (or that should be skipped because a predecessor failed).
+    fn complete(&mut self, issue: u32, succeeded: bool) -> (Vec<u32>, Vec<u32>) {
+        let state = if succeeded {
+            NodeState::Succeeded
+        } else {
+            NodeState::Failed
+        };
+        self.results.insert(issue, state);
+        self.outstanding = self.outstanding.saturating_sub(1);
+
+        let mut ready = Vec::new();
+        let mut skipped = Vec::new();
+        let dependents = self.dependents.get(&issue).cloned().unwrap_or_default();
+        for dependent in dependents {
+            if succeeded {
+                let remaining = self.pending.get_mut(&dependent).expect("dependent tracked");
+                *remaining -= 1;
+                if *remaining == 0 {
+                    ready.push(dependent);
+                }
+            } else {
+                skipped.push(dependent);
+            }
+        }
+        (ready, skipped)
+    }
+
+    /// Mark an issue (and transitively its dependents) as skipped because a
+    /// predecessor failed, returning every issue newly skipped.
+    fn skip(&mut self, issue: u32) -> Vec<u32> {
+        let mut newly = Vec::new();
+        let mut queue = VecDeque::from([issue]);
+        while let Some(current) = queue.pop_front() {
+            if self.results.contains_key(&current) {
+                continue;
+            }
+            self.results.insert(current, NodeState::Skipped);
+            self.outstanding = self.outstanding.saturating_sub(1);
+            newly.push(current);
+            for dependent in self.dependents.get(&current).cloned().unwrap_or_default() {
+                queue.push_back(dependent);
+            }
+        }
+        newly
+    }
+}
+
+fn worker(
+    shared: Arc<(Mutex<Scheduler>, Condvar)>,
+    progress: Arc<MultiProgress>,
+    overall: Arc<ProgressBar>,
+    max_iterations: u32,
+    runner: Runner,
+) {
+    let (lock, cvar) = &*shared;
+    loop {
+        // Wait for an issue to become ready, or exit once the run is complete.
+        let issue = {
+            let mut guard = lock.lock().expect("scheduler poisoned");
+            loop {
+                if let Some(issue) = guard.ready.pop_front() {
+                    break issue;
+                }
+                if guard.done {
+                    return;
+                }
+                guard = cvar.wait(guard).expect("scheduler poisoned");
+            }
+        };
+
+        let succeeded = run_issue(issue, max_iterations, runner, &progress);
+
+        let skipped = {
+            let mut guard = lock.lock().expect("scheduler poisoned");
+            let (ready, skipped_roots) = guard.complete(issue, succeeded);
+            overall.inc(1);
+            guard.ready.extend(ready);
+
+            let mut all_skipped = Vec::new();
+            for dependent in skipped_roots {
+                all_skipped.extend(guard.skip(dependent));
+            }
+            for _ in &all_skipped {
+                overall.inc(1);
+            }
+
+            if guard.remaining() == 0 {
+                guard.done = true;
+            }
+            // Wake every idle worker: new work may be ready, or the run is done.
+            cvar.notify_all();
+            all_skipped
+        };
+
+        for skipped_issue in skipped {
+            progress.suspend(|| println!("issue #{skipped_issue} skipped: a dependency failed"));
+        }
+    }
+}
+
+/// Orchestrate a single issue, updating its own progress bar once per iteration.
+/// Returns whether an iteration succeeded.
+fn run_issue(issue: u32, max_iterations: u32, runner: Runner, progress: &MultiProgress) -> bool {
+    let bar = progress.add(ProgressBar::new(max_iterations as u64));
+    bar.set_style(
+        ProgressStyle::with_template("{prefix:>12} [{bar:30}] iter {pos}/{len}")
+            .expect("valid template")
+            .progress_chars("=> "),
+    );
+    bar.set_prefix(format!("issue #{issue}"));
+
+    // Hand the issue number to the runner so distinct nodes drive distinct
+    // orchestration runs rather than an identical argument-less command.
+    let args = vec!["--issue".to_string(), issue.to_string()];
+    let mut succeeded = false;
+    for iteration in 1..=max_iterations {
+        bar.set_position(iteration as u64);
+        match runner.run(&args, None) {
+            Ok(outcome) if outcome.succeeded() => {
+                succeeded = true;
+                break;
+            }
+            Ok(_) => {}
+            Err(e) => {
+                progress.suspend(|| eprintln!("issue #{issue} iteration {iteration}: {e}"));
+                break;
+            }
+        }
+    }
+
+    if succeeded {
+        bar.finish_with_message("done");
+    } else {
+        bar.abandon_with_message("failed");
+    }
+    bar.finish_and_clear();
+    succeeded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn adjacency(nodes: &[IssueNode]) -> BTreeMap<u32, Vec<u32>> {
+        nodes
+            .iter()
+            .map(|n| (n.issue, n.depends_on.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn build_nodes_wires_dependencies() {
+        let nodes = build_nodes(&[1, 2], &["2:1".to_string()]).unwrap();
+        assert_eq!(
+            nodes,
+            vec![
+                IssueNode {
+                    issue: 1,
+                    depends_on: vec![]
+                },
+                IssueNode {
+                    issue: 2,
+                    depends_on: vec![1]
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn build_nodes_rejects_unknown_issue() {
+        let err = build_nodes(&[1], &["1:9".to_string()]).unwrap_err();
+        assert!(err.contains("#9"), "error was: {err}");
+    }
+
+    #[test]
+    fn build_nodes_rejects_malformed_spec() {
+        let err = build_nodes(&[1], &["nope".to_string()]).unwrap_err();
+        assert!(err.contains("expected `child:parent`"), "error was: {err}");
+    }
+
+    #[test]
+    fn find_cycle_reports_the_loop() {
+        let nodes = adjacency(&[
+            IssueNode {
+                issue: 1,
+                depends_on: vec![2],
+            },
+            IssueNode {
+                issue: 2,
+                depends_on: vec![1],
+            },
+        ]);
+        let cycle = find_cycle(&nodes).expect("cycle present");
+        assert!(cycle.contains(&1) && cycle.contains(&2));
+    }
+
+    #[test]
+    fn find_cycle_accepts_a_dag() {
+        let nodes = adjacency(&[
+            IssueNode {
+                issue: 1,
+                depends_on: vec![],
+            },
+            IssueNode {
+                issue: 2,
+                depends_on: vec![1],
+            },
+            IssueNode {
+                issue: 3,
+                depends_on: vec![1, 2],
+            },
+        ]);
+        assert!(find_cycle(&nodes).is_none());
+    }
+
+    #[test]
+    fn skip_cascades_to_transitive_dependents() {
+        let mut scheduler = Scheduler::new(vec![
+            IssueNode {
+                issue: 1,
+                depends_on: vec![],
+            },
+            IssueNode {
+                issue: 2,
+                depends_on: vec![1],
+            },
+            IssueNode {
+                issue: 3,
+                depends_on: vec![2],
+            },
+        ]);
+        let (ready, skipped) = scheduler.complete(1, false);
+        assert!(ready.is_empty());
+        assert_eq!(skipped, vec![2]);
+        let cascaded = scheduler.skip(2);
+        assert_eq!(cascaded, vec![2, 3]);
+        assert_eq!(scheduler.results[&3], NodeState::Skipped);
+    }
+}
@@ -1,11 +1,32 @@
 mod cli;
+mod config;
+mod dag;
+mod runner;
+mod server;
+
+use std::time::Duration;
 
 use clap::Parser;
 use cli::{Cli, Commands};
+use config::Config;
+use server::ServeConfig;
 
 fn main() {
     let cli = Cli::parse();
 
+    // Layer the configuration file under the CLI: values loaded here seed the
+    // defaults, and each command applies its flags on top.
+    let config = match config::resolve_config_path(cli.config) {
+        Some(path) => match Config::from_path(&path) {
+            Ok(config) => config,
+            Err(e) => {
+                eprintln!("{e}");
+                std::process::exit(2);
+            }
+        },
+        None => Config::default(),
+    };
+
     match cli.command {
         Commands::Init { issue, repo } => {
             println!("Initializing issue #{issue} for {repo}");
@@ -14,9 +35,106 @@ fn main() {
         Commands::Run {
             max_iterations,
             runner,
+            no_fail_fast,
+            issues,
+            after,
+            parallelism,
         } => {
+            let runner = runner.unwrap_or(config.runner);
+            let max_iterations = max_iterations.unwrap_or(config.max_iterations);
+
+            // Batch mode: schedule the requested issues as a dependency DAG.
+            // The scheduler already surfaces every failed/skipped node at the
+            // end rather than aborting the whole batch, so `--no-fail-fast`
+            // has no distinct meaning here; reject the combination rather than
+            // silently ignoring the flag.
+            if !issues.is_empty() {
+                if no_fail_fast {
+                    eprintln!("--no-fail-fast is not supported with --issue batch mode");
+                    std::process::exit(2);
+                }
+                // A single issue with no dependencies still routes through the
+                // scheduler: it is a one-node DAG, which the scheduler handles
+                // uniformly (one per-issue progress bar plus the overall bar).
+                // This keeps one code path for the batch flags at the cost of a
+                // progress bar for the trivial case, which is acceptable.
+                run_batch(&issues, &after, parallelism, max_iterations, runner);
+                return;
+            }
+
             println!("Running orchestrator with {runner} for max {max_iterations} iterations");
-            // TODO: Implement run logic in T4
+
+            // Iterations that exited non-zero or failed to launch, recorded for
+            // the end-of-run summary when `--no-fail-fast` is in effect.
+            let mut failures: Vec<(u32, String)> = Vec::new();
+
+            for iteration in 1..=max_iterations {
+                println!("--- iteration {iteration}/{max_iterations} ---");
+                let failure = match runner.run(&[], None) {
+                    Ok(outcome) => {
+                        println!(
+                            "iteration {iteration} exited {} in {:.2?}",
+                            outcome.exit_status, outcome.duration
+                        );
+                        if outcome.succeeded() {
+                            break;
+                        }
+                        format!("exited with status {}", outcome.exit_status)
+                    }
+                    Err(e) => format!("failed to run: {e}"),
+                };
+
+                if no_fail_fast {
+                    eprintln!("iteration {iteration} {failure}; continuing");
+                    failures.push((iteration, failure));
+                } else {
+                    eprintln!("iteration {iteration} {failure}");
+                    std::process::exit(1);
+                }
+            }
+
+            if !failures.is_empty() {
+                eprintln!("\n{} iteration(s) failed:", failures.len());
+                for (iteration, failure) in &failures {
+                    eprintln!("  iteration {iteration}: {failure}");
+                }
+                std::process::exit(1);
+            }
+        }
+        Commands::Serve {
+            listen,
+            secret,
+            max_jobs,
+            job_timeout,
+        } => {
+            let listen = listen
+                .or(config.listen)
+                .unwrap_or_else(|| "0.0.0.0:8080".to_string());
+            let secret = match secret.or(config.webhook_secret) {
+                Some(secret) => secret,
+                None => {
+                    eprintln!(
+                        "a webhook secret is required: pass --secret, set \
+                         JEEVES_WEBHOOK_SECRET, or configure `webhook_secret`"
+                    );
+                    std::process::exit(2);
+                }
+            };
+            let job_timeout = job_timeout
+                .map(Duration::from_secs)
+                .unwrap_or(config.job_timeout);
+            let serve_config = ServeConfig {
+                listen,
+                secret,
+                max_jobs,
+                job_timeout,
+                runner: config.runner,
+                max_iterations: config.max_iterations,
+            };
+            if let Err(e) = server::serve(serve_config) {
+                eprintln!("serve failed: {e}");
+                std::process::exit(1);
+            }
         }
         Commands::Sonar => {
             println!("Fetching SonarCloud issues");
@@ -28,3 +146,48 @@ fn main() {
         }
     }
 }
+
+/// Orchestrate a batch of dependent issues as a DAG, then exit non-zero if any
+/// issue failed or was skipped because a predecessor failed.
+fn run_batch(
+    issues: &[u32],
+    after: &[String],
+    parallelism: usize,
+    max_iterations: u32,
+    runner: runner::Runner,
+) {
+    let nodes = match dag::build_nodes(issues, after) {
+        Ok(nodes) => nodes,
+        Err(e) => {
+            eprintln!("{e}");
+            std::process::exit(2);
+        }
+    };
+
+    let results = match dag::schedule(nodes, parallelism, max_iterations, runner) {
+        Ok(results) => results,
+        Err(e) => {
+            eprintln!("{e}");
+            std::process::exit(2);
+        }
+    };
+
+    let mut failed = Vec::new();
+    for (issue, state) in &results {
+        match state {
+            dag::NodeState::Succeeded => println!("issue #{issue}: succeeded"),
+            dag::NodeState::Failed => {
+                println!("issue #{issue}: failed");
+                failed.push(*issue);
+            }
+            dag::NodeState::Skipped => {
+                println!("issue #{issue}: skipped (dependency failed)");
+                failed.push(*issue);
+            }
+        }
+    }
+
+    if !failed.is_empty() {
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,220 @@
+use std::io::{BufRead, BufReader};
+use std::process::{Command, Stdio};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Polling interval while waiting for a child under a timeout.
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+use clap::ValueEnum;
+
+/// An agent backend that `jeeves` can drive for a single orchestration
+/// iteration. Each variant maps to an executable on `PATH`.
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+#[value(rename_all = "lowercase")]
+pub enum Runner {
+    Claude,
+    Codex,
+    Opencode,
+}
+
+impl Runner {
+    /// The executable name invoked for this runner.
+    pub fn command(&self) -> &'static str {
+        match self {
+            Runner::Claude => "claude",
+            Runner::Codex => "codex",
+            Runner::Opencode => "opencode",
+        }
+    }
+}
+
+impl std::fmt::Display for Runner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.command())
+    }
+}
+
+/// The captured result of running an agent for one iteration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RunnerOutcome {
+    pub stdout: String,
+    pub stderr: String,
+    pub exit_status: i32,
+    pub duration: Duration,
+    /// Set when the child was killed for exceeding its timeout.
+    pub timed_out: bool,
+}
+
+impl RunnerOutcome {
+    /// Whether the iteration exited cleanly (status 0) and was not timed out.
+    pub fn succeeded(&self) -> bool {
+        self.exit_status == 0 && !self.timed_out
+    }
+}
+
+impl Runner {
+    /// Launch this runner as a child process with `args`, streaming its stdout
+    /// and stderr to the console line-by-line while retaining the full output,
+    /// and recording wall-clock duration and exit code.
+    ///
+    /// When `timeout` is `Some`, the child is killed once its wall-clock exceeds
+    /// the limit and the returned outcome is flagged [`RunnerOutcome::timed_out`].
+    pub fn run(&self, args: &[String], timeout: Option<Duration>) -> Result<RunnerOutcome, String> {
+        let started = Instant::now();
+        let mut child = Command::new(self.command())
+            .args(args)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| format!("failed to launch {}: {e}", self.command()))?;
+
+        let stdout = child.stdout.take().expect("stdout was piped");
+        let stderr = child.stderr.take().expect("stderr was piped");
+
+        // Drain both streams concurrently into shared buffers so a chatty
+        // stderr cannot deadlock a full stdout pipe (or vice versa).
+        let out_buf = Arc::new(Mutex::new(String::new()));
+        let err_buf = Arc::new(Mutex::new(String::new()));
+        let out_handle = spawn_tee(stdout, Stream::Stdout, Arc::clone(&out_buf));
+        let err_handle = spawn_tee(stderr, Stream::Stderr, Arc::clone(&err_buf));
+
+        let (status, timed_out) = match timeout {
+            Some(limit) => self.wait_with_timeout(&mut child, started, limit)?,
+            None => {
+                let status = child
+                    .wait()
+                    .map_err(|e| format!("failed to wait on {}: {e}", self.command()))?;
+                (status, false)
+            }
+        };
+
+        // On a clean exit the pipes have closed, so the readers finish promptly
+        // and we join to flush every last line. On a timeout the agent may have
+        // left grandchildren holding the pipe write ends, so joining could block
+        // indefinitely and defeat the timeout — instead we snapshot whatever was
+        // captured and leave the detached readers to wind down on EOF.
+        if !timed_out {
+            out_handle.join().expect("stdout reader panicked");
+            err_handle.join().expect("stderr reader panicked");
+        }
+        let stdout = out_buf.lock().expect("stdout buffer poisoned").clone();
+        let stderr = err_buf.lock().expect("stderr buffer poisoned").clone();
+
+        Ok(RunnerOutcome {
+            stdout,
+            stderr,
+            // `code()` is `None` when the process was killed by a signal; -1 is
+            // the conventional stand-in the loop can treat as a failure.
+            exit_status: status.code().unwrap_or(-1),
+            duration: started.elapsed(),
+            timed_out,
+        })
+    }
+
+    /// Poll the child until it exits or `limit` elapses, killing it on timeout.
+    /// Returns the exit status alongside whether the kill path was taken.
+    fn wait_with_timeout(
+        &self,
+        child: &mut std::process::Child,
+        started: Instant,
+        limit: Duration,
+    ) -> Result<(std::process::ExitStatus, bool), String> {
+        loop {
+            match child
+                .try_wait()
+                .map_err(|e| format!("failed to wait on {}: {e}", self.command()))?
+            {
+                Some(status) => return Ok((status, false)),
+                None if started.elapsed() >= limit => {
+                    // Best-effort kill, then reap so the child isn't left as a
+                    // zombie and the stdout/stderr pipes close for the tees.
+                    let _ = child.kill();
+                    let status = child
+                        .wait()
+                        .map_err(|e| format!("failed to wait on {}: {e}", self.command()))?;
+                    return Ok((status, true));
+                }
+                None => thread::sleep(POLL_INTERVAL),
+            }
+        }
+    }
+}
+
+enum Stream {
+    Stdout,
+    Stderr,
+}
+
+/// Read `reader` line-by-line, echoing each line to the console while appending
+/// it to the shared `buffer` so the caller can read the retained output without
+/// joining the reader thread.
+///
+/// Reading is done over raw bytes (`read_until`) so a non-UTF-8 byte does not
+/// truncate the stream the way `read_line` would; lines are lossily decoded for
+/// display and retention. A genuine read error ends the stream but keeps
+/// whatever was captured so far.
+fn spawn_tee<R>(reader: R, stream: Stream, buffer: Arc<Mutex<String>>) -> thread::JoinHandle<()>
+where
+    R: std::io::Read + Send + 'static,
+{
+    thread::spawn(move || {
+        let mut buffered = BufReader::new(reader);
+        let mut line = Vec::new();
+        loop {
+            line.clear();
+            match buffered.read_until(b'\n', &mut line) {
+                Ok(0) => break,
+                Ok(_) => {
+                    let text = String::from_utf8_lossy(&line);
+                    match stream {
+                        Stream::Stdout => print!("{text}"),
+                        Stream::Stderr => eprint!("{text}"),
+                    }
+                    buffer.lock().expect("output buffer poisoned").push_str(&text);
+                }
+                Err(e) => {
+                    eprintln!("warning: error reading child output: {e}");
+                    break;
+                }
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn command_maps_each_variant() {
+        assert_eq!(Runner::Claude.command(), "claude");
+        assert_eq!(Runner::Codex.command(), "codex");
+        assert_eq!(Runner::Opencode.command(), "opencode");
+    }
+
+    #[test]
+    fn outcome_success_is_status_zero() {
+        let outcome = RunnerOutcome {
+            stdout: String::new(),
+            stderr: String::new(),
+            exit_status: 0,
+            duration: Duration::from_secs(1),
+            timed_out: false,
+        };
+        assert!(outcome.succeeded());
+
+        let failed = RunnerOutcome {
+            exit_status: 2,
+            ..outcome.clone()
+        };
+        assert!(!failed.succeeded());
+
+        let timed_out = RunnerOutcome {
+            timed_out: true,
+            ..outcome
+        };
+        assert!(!timed_out.succeeded());
+    }
+}
@@ -0,0 +1,382 @@
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use tiny_http::{Method, Request, Response, Server};
+
+use crate::runner::Runner;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// A single unit of work extracted from a webhook delivery: an orchestration
+/// run for one issue in one repository.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Job {
+    pub repo: String,
+    pub issue: u32,
+}
+
+/// Configuration for the long-running webhook server.
+#[derive(Debug, Clone)]
+pub struct ServeConfig {
+    /// Address to bind the HTTP listener to, e.g. `0.0.0.0:8080`.
+    pub listen: String,
+    /// Shared secret used to verify `X-Hub-Signature-256` on each delivery.
+    pub secret: String,
+    /// Maximum number of orchestration runs executing in parallel; additional
+    /// deliveries are queued until a worker frees up.
+    pub max_jobs: usize,
+    /// Wall-clock limit for a single orchestration run.
+    pub job_timeout: Duration,
+    /// Runner to launch for each delivery.
+    pub runner: Runner,
+    /// Maximum iterations per orchestration run.
+    pub max_iterations: u32,
+}
+
+/// Verify a GitHub webhook delivery signature.
+///
+/// Computes HMAC-SHA256 over the raw request `body` keyed by `secret` and
+/// compares it against the `sha256=<hex>` value from the `X-Hub-Signature-256`
+/// header. The comparison is constant-time (delegated to the MAC's own
+/// verification), and a missing or malformed header is treated as a mismatch.
+pub fn verify_signature(secret: &str, body: &[u8], header: Option<&str>) -> bool {
+    let header = match header {
+        Some(h) => h,
+        None => return false,
+    };
+    let hex = match header.strip_prefix("sha256=") {
+        Some(h) => h,
+        None => return false,
+    };
+    let expected = match decode_hex(hex) {
+        Some(bytes) => bytes,
+        None => return false,
+    };
+
+    let mut mac = HmacSha256::new_from_slice(secret.as_bytes())
+        .expect("HMAC can accept a key of any length");
+    mac.update(body);
+    mac.verify_slice(&expected).is_ok()
+}
+
+/// Decode a lowercase/uppercase hex string into bytes, returning `None` on any
+/// non-hex or odd-length input so callers can reject the delivery.
+///
+/// Works over raw bytes so a non-ASCII header cannot trigger a char-boundary
+/// panic on a hostile delivery.
+fn decode_hex(s: &str) -> Option<Vec<u8>> {
+    let bytes = s.as_bytes();
+    let mut chunks = bytes.chunks_exact(2);
+    let mut out = Vec::with_capacity(bytes.len() / 2);
+    for pair in &mut chunks {
+        let hi = (pair[0] as char).to_digit(16)?;
+        let lo = (pair[1] as char).to_digit(16)?;
+        out.push((hi * 16 + lo) as u8);
+    }
+    if !chunks.remainder().is_empty() {
+        return None;
+    }
+    Some(out)
+}
+
+/// Look up a header value by its (case-insensitive) field name.
+fn header_value<'a>(request: &'a Request, name: &str) -> Option<&'a str> {
+    request
+        .headers()
+        .iter()
+        .find(|h| h.field.as_str().as_str().eq_ignore_ascii_case(name))
+        .map(|h| h.value.as_str())
+}
+
+/// Extract the issue number from an `issues`/`issue_comment` webhook payload.
+fn parse_issue(body: &[u8]) -> Option<Job> {
+    let payload: serde_json::Value = serde_json::from_slice(body).ok()?;
+    let issue = payload.get("issue")?.get("number")?.as_u64()? as u32;
+    let repo = payload
+        .get("repository")?
+        .get("full_name")?
+        .as_str()?
+        .to_string();
+    Some(Job { repo, issue })
+}
+
+/// A bounded pool of worker threads that execute orchestration runs. Jobs
+/// submitted beyond the pool's capacity wait in an internal queue.
+struct WorkerPool {
+    // `Option` so the sender can be dropped in `Drop` ahead of joining, closing
+    // the channel and letting the worker threads observe shutdown.
+    sender: Option<Sender<Job>>,
+    workers: Vec<thread::JoinHandle<()>>,
+}
+
+impl WorkerPool {
+    fn new(size: usize, runner: Runner, max_iterations: u32, job_timeout: Duration) -> WorkerPool {
+        let (sender, receiver) = mpsc::channel::<Job>();
+        let receiver = Arc::new(Mutex::new(receiver));
+        let mut workers = Vec::with_capacity(size);
+        for id in 0..size {
+            let receiver = Arc::clone(&receiver);
+            workers.push(thread::spawn(move || {
+                worker_loop(id, receiver, runner, max_iterations, job_timeout)
+            }));
+        }
+        WorkerPool {
+            sender: Some(sender),
+            workers,
+        }
+    }
+
+    fn submit(&self, job: Job) {
+        // The receivers live for the lifetime of the pool, so a send only fails
+        // during shutdown, where dropping the job is the intended behaviour.
+        if let Some(sender) = &self.sender {
+            let _ = sender.send(job);
+        }
+    }
+}
+
+impl Drop for WorkerPool {
+    fn drop(&mut self) {
+        // Close the channel first so idle workers fall out of `recv`, then wait
+        // for every in-flight run to finish.
+        drop(self.sender.take());
+        for worker in self.workers.drain(..) {
+            let _ = worker.join();
+        }
+    }
+}
+
+fn worker_loop(
+    id: usize,
+    receiver: Arc<Mutex<Receiver<Job>>>,
+    runner: Runner,
+    max_iterations: u32,
+    job_timeout: Duration,
+) {
+    loop {
+        let job = {
+            let guard = receiver.lock().expect("worker queue poisoned");
+            guard.recv()
+        };
+        match job {
+            Ok(job) => run_job(id, &job, runner, max_iterations, job_timeout),
+            Err(_) => break,
+        }
+    }
+}
+
+/// Execute a single orchestration run for a job by driving the selected runner,
+/// bounding the whole run to `job_timeout`.
+///
+/// The job's issue and repository are threaded into the child invocation so the
+/// agent knows which issue it is orchestrating. The timeout is enforced inside
+/// [`Runner::run`], which kills the runner child when the remaining budget is
+/// spent and returns promptly so the worker is freed for the next delivery.
+/// (Only the direct child is signalled; any further processes it spawned are
+/// left to the OS.)
+fn run_job(worker: usize, job: &Job, runner: Runner, max_iterations: u32, job_timeout: Duration) {
+    println!(
+        "[worker {worker}] orchestrating issue #{} for {}",
+        job.issue, job.repo
+    );
+
+    let args = vec![
+        "--issue".to_string(),
+        job.issue.to_string(),
+        "--repo".to_string(),
+        job.repo.clone(),
+    ];
+
+    // `checked_add` avoids panicking on an absurdly large configured timeout;
+    // on overflow we fall back to bounding each iteration by the raw duration.
+    let deadline = Instant::now().checked_add(job_timeout);
+    for iteration in 1..=max_iterations {
+        let remaining = match deadline {
+            Some(deadline) => deadline.saturating_duration_since(Instant::now()),
+            None => job_timeout,
+        };
+        if remaining.is_zero() {
+            eprintln!(
+                "[worker {worker}] issue #{} exceeded {:?}; abandoning run",
+                job.issue, job_timeout
+            );
+            return;
+        }
+        match runner.run(&args, Some(remaining)) {
+            Ok(outcome) if outcome.timed_out => {
+                eprintln!(
+                    "[worker {worker}] issue #{} iteration {iteration} timed out; killed runner",
+                    job.issue
+                );
+                return;
+            }
+            Ok(outcome) if outcome.succeeded() => {
+                println!("[worker {worker}] issue #{} finished", job.issue);
+                return;
+            }
+            Ok(_) => continue,
+            Err(e) => {
+                eprintln!("[worker {worker}] iteration {iteration} failed: {e}");
+                return;
+            }
+        }
+    }
+    eprintln!(
+        "[worker {worker}] issue #{} exhausted {max_iterations} iterations without success",
+        job.issue
+    );
+}
+
+/// Run the webhook server until the process is terminated.
+///
+/// Binds the listener, then serves deliveries on the calling thread, handing
+/// verified `issues`/`issue_comment` events off to a bounded worker pool.
+pub fn serve(config: ServeConfig) -> Result<(), String> {
+    let server = Server::http(&config.listen)
+        .map_err(|e| format!("failed to bind {}: {e}", config.listen))?;
+    println!(
+        "jeeves serving webhooks on {} (max {} concurrent jobs)",
+        config.listen, config.max_jobs
+    );
+
+    let pool = WorkerPool::new(
+        config.max_jobs.max(1),
+        config.runner,
+        config.max_iterations,
+        config.job_timeout,
+    );
+
+    for request in server.incoming_requests() {
+        handle_request(request, &config, &pool);
+    }
+    Ok(())
+}
+
+fn handle_request(mut request: Request, config: &ServeConfig, pool: &WorkerPool) {
+    if *request.method() != Method::Post {
+        respond(request, 405, "method not allowed");
+        return;
+    }
+
+    let mut body = Vec::new();
+    if std::io::Read::read_to_end(request.as_reader(), &mut body).is_err() {
+        respond(request, 400, "unable to read request body");
+        return;
+    }
+
+    let signature = header_value(&request, "X-Hub-Signature-256").map(str::to_string);
+    if !verify_signature(&config.secret, &body, signature.as_deref()) {
+        respond(request, 400, "signature verification failed");
+        return;
+    }
+
+    let is_issue_event = matches!(
+        header_value(&request, "X-GitHub-Event"),
+        Some("issues") | Some("issue_comment")
+    );
+    if !is_issue_event {
+        respond(request, 202, "ignored event");
+        return;
+    }
+
+    match parse_issue(&body) {
+        Some(job) => {
+            pool.submit(job);
+            respond(request, 202, "accepted");
+        }
+        None => respond(request, 400, "missing issue in payload"),
+    }
+}
+
+fn respond(request: Request, status: u16, message: &str) {
+    let response = Response::from_string(message).with_status_code(status);
+    // A failed write just means the client hung up; nothing to recover.
+    let _ = request.respond(response);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Build the `sha256=<hex>` header value for `body` keyed by `secret`, the
+    /// way GitHub signs a delivery.
+    fn sign(secret: &str, body: &[u8]) -> String {
+        let mut mac = HmacSha256::new_from_slice(secret.as_bytes()).unwrap();
+        mac.update(body);
+        format!("sha256={}", encode_hex(&mac.finalize().into_bytes()))
+    }
+
+    fn encode_hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{b:02x}")).collect()
+    }
+
+    #[test]
+    fn decode_hex_round_trips() {
+        assert_eq!(decode_hex("00ff10"), Some(vec![0x00, 0xff, 0x10]));
+        assert_eq!(decode_hex("0"), None);
+        assert_eq!(decode_hex("zz"), None);
+    }
+
+    #[test]
+    fn decode_hex_rejects_non_ascii_without_panicking() {
+        // A multi-byte char must be rejected rather than slicing mid-codepoint.
+        assert_eq!(decode_hex("€x"), None);
+    }
+
+    #[test]
+    fn non_ascii_signature_is_rejected() {
+        assert!(!verify_signature("secret", b"payload", Some("sha256=€x")));
+    }
+
+    #[test]
+    fn valid_signature_is_accepted() {
+        let body = br#"{"issue":{"number":1}}"#;
+        let header = sign("secret", body);
+        assert!(verify_signature("secret", body, Some(&header)));
+    }
+
+    #[test]
+    fn tampered_body_is_rejected() {
+        let header = sign("secret", b"original");
+        assert!(!verify_signature("secret", b"tampered", Some(&header)));
+    }
+
+    #[test]
+    fn wrong_secret_is_rejected() {
+        let body = b"payload";
+        let header = sign("secret", body);
+        assert!(!verify_signature("other", body, Some(&header)));
+    }
+
+    #[test]
+    fn missing_header_is_rejected() {
+        assert!(!verify_signature("secret", b"payload", None));
+    }
+
+    #[test]
+    fn malformed_header_is_rejected() {
+        assert!(!verify_signature("secret", b"payload", Some("deadbeef")));
+    }
+
+    #[test]
+    fn parse_issue_extracts_number_and_repo() {
+        let body = br#"{"issue":{"number":42},"repository":{"full_name":"owner/repo"}}"#;
+        assert_eq!(
+            parse_issue(body),
+            Some(Job {
+                repo: "owner/repo".to_string(),
+                issue: 42
+            })
+        );
+    }
+
+    #[test]
+    fn parse_issue_rejects_payload_without_issue() {
+        let body = br#"{"repository":{"full_name":"owner/repo"}}"#;
+        assert_eq!(parse_issue(body), None);
+    }
+}